@@ -4,8 +4,11 @@
 //! allowing the Flint testing framework to create test worlds using the real steel-core
 //! World implementation.
 
+use std::sync::Arc;
+
 use flint_core::{FlintAdapter, FlintWorld, ServerInfo};
 
+use crate::alias::BlockAliases;
 use crate::world::SteelTestWorld;
 
 /// Adapter for running Flint tests against `SteelMC`.
@@ -15,20 +18,39 @@ use crate::world::SteelTestWorld;
 pub struct SteelAdapter {
     /// Server info for identification
     info: ServerInfo,
+    /// Block ID aliases shared by all test worlds
+    aliases: Arc<BlockAliases>,
 }
 
 impl SteelAdapter {
     /// Creates a new Steel adapter.
     ///
-    /// Note: You must call `steel_flint::init()` before creating an adapter.
+    /// Note: You must call `flint_steel::init()` before creating an adapter.
     #[must_use]
     pub fn new() -> Self {
         Self {
             info: ServerInfo {
                 minecraft_version: "1.21.11".to_string(),
             },
+            aliases: Arc::default(),
         }
     }
+
+    /// Sets the block ID aliases applied when test worlds place blocks.
+    ///
+    /// This lets one spec corpus run against versions that renamed blocks
+    /// (e.g. `grass` → `short_grass`).
+    #[must_use]
+    pub fn with_aliases(mut self, aliases: BlockAliases) -> Self {
+        self.aliases = Arc::new(aliases);
+        self
+    }
+
+    /// Returns the block ID aliases used by this adapter's test worlds.
+    #[must_use]
+    pub fn aliases(&self) -> &BlockAliases {
+        &self.aliases
+    }
 }
 
 impl Default for SteelAdapter {
@@ -39,7 +61,7 @@ impl Default for SteelAdapter {
 
 impl FlintAdapter for SteelAdapter {
     fn create_test_world(&self) -> Box<dyn FlintWorld> {
-        Box::new(SteelTestWorld::new())
+        Box::new(SteelTestWorld::with_aliases(self.aliases.clone()))
     }
 
     fn server_info(&self) -> ServerInfo {
@@ -51,11 +73,10 @@ impl FlintAdapter for SteelAdapter {
 mod tests {
     use super::*;
     use crate::init_test_registries;
-    use crate::{TestLoader, TestRunner};
+    use crate::{EnvTestFilter, TestLoader, TestRunner};
     use dotenvy::dotenv;
     use flint_core::test_spec;
     use flint_core::utils::get_test_path;
-    use std::env::var;
     use std::path::PathBuf;
     use test_spec::TestSpec;
 
@@ -63,64 +84,6 @@ mod tests {
         dotenv().ok();
     }
 
-    /// Collects test file paths based on environment variables.
-    /// Priority: `FLINT_TEST` > `FLINT_PATTERN` > `FLINT_TAGS` > all
-    fn collect_filtered_paths(loader: &TestLoader) -> Vec<PathBuf> {
-        // Single test by name
-        if let Ok(test_name) = var("FLINT_TEST") {
-            println!("Running single test: {test_name}");
-            return loader
-                .collect_all_test_files()
-                .unwrap_or_default()
-                .into_iter()
-                .filter(|p| {
-                    p.file_stem()
-                        .and_then(|s| s.to_str())
-                        .is_some_and(|name| name == test_name)
-                })
-                .collect();
-        }
-
-        // Pattern matching (glob-style)
-        if let Ok(pattern) = var("FLINT_PATTERN") {
-            println!("Running tests matching pattern: {pattern}");
-            return loader
-                .collect_all_test_files()
-                .unwrap_or_default()
-                .into_iter()
-                .filter(|p| {
-                    p.file_stem()
-                        .and_then(|s| s.to_str())
-                        .is_some_and(|name| matches_pattern(name, &pattern))
-                })
-                .collect();
-        }
-
-        // Tag filtering
-        if let Ok(tags_str) = var("FLINT_TAGS") {
-            let tags: Vec<String> = tags_str.split(',').map(|s| s.trim().to_string()).collect();
-            println!("Running tests with tags: {}", tags.join(", "));
-            return loader.collect_by_tags(&tags).unwrap_or_default();
-        }
-
-        // Default: all tests
-        println!("Running all flint tests");
-        loader.collect_all_test_files().unwrap_or_default()
-    }
-
-    /// Simple glob pattern matching (supports * wildcard)
-    fn matches_pattern(name: &str, pattern: &str) -> bool {
-        if pattern == "*" {
-            return true;
-        }
-        if let Some(prefix) = pattern.strip_suffix('*') {
-            return name.starts_with(prefix);
-        }
-        if let Some(suffix) = pattern.strip_prefix('*') {
-            return name.ends_with(suffix);
-        }
-        name == pattern
-    }
     fn generate_test_specs(paths: Vec<PathBuf>) -> Vec<TestSpec> {
         paths
             .iter()
@@ -141,7 +104,11 @@ mod tests {
         let test_path = PathBuf::from(get_test_path());
         let loader = TestLoader::new(&test_path, true)
             .unwrap_or_else(|e| panic!("error while loading test files: {e}"));
-        let paths = collect_filtered_paths(&loader);
+        let filter = EnvTestFilter::from_env();
+        println!("Running {filter}");
+        let paths = filter
+            .collect_paths(&loader)
+            .unwrap_or_else(|e| panic!("error while collecting test files: {e}"));
         let specs: Vec<TestSpec> = generate_test_specs(paths);
 
         // Create adapter and runner
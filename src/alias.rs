@@ -0,0 +1,201 @@
+//! Block ID aliasing for running one spec corpus across Minecraft versions.
+//!
+//! Block IDs get renamed between versions (e.g. `grass` became `short_grass` in
+//! 1.20.3). [`BlockAliases`] maps old IDs to the IDs this adapter understands and
+//! is applied when converting Flint blocks to `SteelMC` block states.
+//!
+//! Assertions are compared by the flint-core runner, which does not apply the
+//! alias map itself. Runners can normalize expected blocks with
+//! [`BlockAliases::apply`] before comparing.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{error, fmt, fs, io};
+
+use flint_core::Block;
+
+/// An error while loading block aliases.
+#[derive(Debug)]
+pub enum AliasError {
+    /// The alias file could not be read.
+    Io(io::Error),
+    /// The alias JSON is not an object of string values.
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for AliasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read alias file: {e}"),
+            Self::Parse(e) => write!(f, "invalid alias JSON: {e}"),
+        }
+    }
+}
+
+impl error::Error for AliasError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for AliasError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for AliasError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// A map of block ID aliases.
+///
+/// IDs are stored without the `minecraft:` namespace, so `grass` and
+/// `minecraft:grass` are treated the same.
+#[derive(Debug, Clone, Default)]
+pub struct BlockAliases {
+    /// Alias → target block ID, both without namespace.
+    aliases: BTreeMap<String, String>,
+}
+
+impl BlockAliases {
+    /// Creates an empty alias map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses aliases from a JSON object mapping old IDs to new IDs.
+    ///
+    /// ```json
+    /// { "grass": "short_grass", "minecraft:chain": "minecraft:iron_chain" }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is not an object of string values.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let raw: BTreeMap<String, String> = serde_json::from_str(json)?;
+        Ok(raw.into_iter().collect())
+    }
+
+    /// Loads aliases from a JSON file (see [`from_json`](Self::from_json)).
+    ///
+    /// # Errors
+    /// Returns [`AliasError::Io`] if the file cannot be read and
+    /// [`AliasError::Parse`] if it is not valid alias JSON.
+    pub fn from_file(path: &Path) -> Result<Self, AliasError> {
+        let json = fs::read_to_string(path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    /// Adds an alias, replacing any previous target for `from`.
+    pub fn insert(&mut self, from: &str, to: &str) {
+        self.aliases.insert(
+            strip_namespace(from).to_string(),
+            strip_namespace(to).to_string(),
+        );
+    }
+
+    /// Resolves a block ID (without namespace) to its alias target.
+    ///
+    /// Returns the ID unchanged if it has no alias.
+    #[must_use]
+    pub fn resolve<'a>(&'a self, id: &'a str) -> &'a str {
+        self.aliases.get(id).map_or(id, String::as_str)
+    }
+
+    /// Returns `block` with its ID resolved through the alias map.
+    ///
+    /// Properties are kept unchanged. This is the same resolution placements
+    /// use, so runners can apply it to expected blocks before comparing.
+    #[must_use]
+    pub fn apply(&self, block: &Block) -> Block {
+        let mut resolved = block.clone();
+        let id = strip_namespace(&block.id);
+        let target = self.resolve(id);
+        if target != id {
+            resolved.id = format!("minecraft:{target}");
+        }
+        resolved
+    }
+
+    /// Returns the number of aliases.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// Returns `true` if there are no aliases.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+}
+
+impl FromIterator<(String, String)> for BlockAliases {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        let mut aliases = Self::new();
+        for (from, to) in iter {
+            aliases.insert(&from, &to);
+        }
+        aliases
+    }
+}
+
+/// Strips the `minecraft:` namespace from an ID, if present.
+fn strip_namespace(id: &str) -> &str {
+    id.strip_prefix("minecraft:").unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_alias() {
+        let mut aliases = BlockAliases::new();
+        aliases.insert("minecraft:grass", "short_grass");
+
+        assert_eq!(aliases.resolve("grass"), "short_grass");
+        assert_eq!(aliases.resolve("stone"), "stone");
+    }
+
+    #[test]
+    fn test_aliases_from_json() {
+        let aliases =
+            BlockAliases::from_json(r#"{ "grass": "minecraft:short_grass", "minecraft:a": "b" }"#)
+                .expect("valid alias json");
+
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases.resolve("grass"), "short_grass");
+        assert_eq!(aliases.resolve("a"), "b");
+    }
+
+    #[test]
+    fn test_invalid_alias_json() {
+        assert!(BlockAliases::from_json(r#"{ "grass": 1 }"#).is_err());
+    }
+
+    #[test]
+    fn test_missing_alias_file() {
+        let result = BlockAliases::from_file(Path::new("does/not/exist.json"));
+        assert!(matches!(result, Err(AliasError::Io(_))));
+    }
+
+    #[test]
+    fn test_apply_alias_to_block() {
+        let mut aliases = BlockAliases::new();
+        aliases.insert("grass", "short_grass");
+
+        assert_eq!(
+            aliases.apply(&Block::new("grass")).id,
+            "minecraft:short_grass"
+        );
+        assert_eq!(aliases.apply(&Block::new("stone")).id, "stone");
+    }
+}
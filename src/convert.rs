@@ -7,16 +7,20 @@ use steel_registry::REGISTRY;
 use steel_registry::blocks::properties::Direction;
 use steel_utils::{BlockPos as SteelBlockPos, BlockStateId, Identifier};
 
+use crate::alias::BlockAliases;
+
 /// Convert a Flint block specification to a `SteelMC` `BlockStateId`.
 ///
+/// The block ID is resolved through `aliases` before the registry lookup.
 /// Returns `None` if the block ID is unknown or if any property is invalid.
-pub fn flint_block_to_state_id(block: &Block) -> Option<BlockStateId> {
+pub fn flint_block_to_state_id(block: &Block, aliases: &BlockAliases) -> Option<BlockStateId> {
     // Parse the block ID - may have "minecraft:" prefix
     let block_id = if block.id.starts_with("minecraft:") {
         &block.id[10..]
     } else {
         &block.id
     };
+    let block_id = aliases.resolve(block_id);
 
     let identifier = Identifier::vanilla(block_id.to_string());
 
@@ -105,7 +109,7 @@ mod tests {
         init_test_registries();
         let block = Block::new("minecraft:stone");
 
-        let state_id = flint_block_to_state_id(&block);
+        let state_id = flint_block_to_state_id(&block, &BlockAliases::default());
         assert!(state_id.is_some(), "Stone should convert to valid state ID");
 
         let retrieved = state_id_to_block(state_id.expect("Valid state ID"));
@@ -117,7 +121,7 @@ mod tests {
         init_test_registries();
         let block = Block::new("minecraft:air");
 
-        let state_id = flint_block_to_state_id(&block);
+        let state_id = flint_block_to_state_id(&block, &BlockAliases::default());
         assert!(state_id.is_some(), "Air should convert to valid state ID");
     }
 
@@ -126,7 +130,21 @@ mod tests {
         init_test_registries();
         let block = Block::new("stone");
 
-        let state_id = flint_block_to_state_id(&block);
+        let state_id = flint_block_to_state_id(&block, &BlockAliases::default());
         assert!(state_id.is_some(), "Block without prefix should still work");
     }
+
+    #[test]
+    fn test_aliased_block() {
+        init_test_registries();
+        let mut aliases = BlockAliases::new();
+        aliases.insert("old_stone", "minecraft:stone");
+        let block = Block::new("minecraft:old_stone");
+
+        let state_id = flint_block_to_state_id(&block, &aliases);
+        assert!(state_id.is_some(), "Aliased block should resolve");
+
+        let retrieved = state_id_to_block(state_id.expect("Valid state ID"));
+        assert_eq!(retrieved.id, "minecraft:stone");
+    }
 }
@@ -0,0 +1,165 @@
+//! Environment-driven test selection.
+//!
+//! This module provides [`EnvTestFilter`], which selects test files based on the
+//! `FLINT_TEST`, `FLINT_PATTERN` and `FLINT_TAGS` environment variables, so every
+//! integrator gets the same selection behavior as `cargo test` in this crate.
+
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+
+use flint_core::TestLoader;
+
+/// Selects which test files to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvTestFilter {
+    /// Run a single test by exact file name (without extension).
+    Name(String),
+    /// Run tests whose file name matches a glob pattern (`prefix*` or `*suffix`).
+    Pattern(String),
+    /// Run tests that have any of the given tags.
+    Tags(Vec<String>),
+    /// Run all tests.
+    All,
+}
+
+impl EnvTestFilter {
+    /// Builds a filter from environment variables.
+    ///
+    /// Precedence: `FLINT_TEST` > `FLINT_PATTERN` > `FLINT_TAGS` > all tests.
+    /// `FLINT_TAGS` is a comma-separated list. Variables from a `.env` file are
+    /// only seen if it has been loaded (e.g. with `dotenvy`) beforehand.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| env::var(key).ok())
+    }
+
+    /// Builds a filter using `lookup` to read variables (see [`from_env`](Self::from_env)).
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(name) = lookup("FLINT_TEST") {
+            return Self::Name(name);
+        }
+
+        if let Some(pattern) = lookup("FLINT_PATTERN") {
+            return Self::Pattern(pattern);
+        }
+
+        if let Some(tags) = lookup("FLINT_TAGS") {
+            return Self::Tags(
+                tags.split(',')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            );
+        }
+
+        Self::All
+    }
+
+    /// Collects the test file paths selected by this filter.
+    ///
+    /// # Errors
+    /// Returns the loader's error if the test directory cannot be read, so a
+    /// broken setup is distinguishable from a filter that selects nothing.
+    pub fn collect_paths(&self, loader: &TestLoader) -> anyhow::Result<Vec<PathBuf>> {
+        match self {
+            Self::Name(name) => collect_by_stem(loader, |stem| stem == name),
+            Self::Pattern(pattern) => {
+                collect_by_stem(loader, |stem| matches_pattern(stem, pattern))
+            }
+            Self::Tags(tags) => Ok(loader.collect_by_tags(tags)?),
+            Self::All => Ok(loader.collect_all_test_files()?),
+        }
+    }
+}
+
+impl fmt::Display for EnvTestFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "single test: {name}"),
+            Self::Pattern(pattern) => write!(f, "tests matching pattern: {pattern}"),
+            Self::Tags(tags) => write!(f, "tests with tags: {}", tags.join(", ")),
+            Self::All => write!(f, "all flint tests"),
+        }
+    }
+}
+
+/// Collects all test files whose file stem satisfies `predicate`.
+fn collect_by_stem(
+    loader: &TestLoader,
+    predicate: impl Fn(&str) -> bool,
+) -> anyhow::Result<Vec<PathBuf>> {
+    Ok(loader
+        .collect_all_test_files()?
+        .into_iter()
+        .filter(|p| {
+            p.file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(&predicate)
+        })
+        .collect())
+}
+
+/// Simple glob pattern matching (supports * wildcard)
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    if let Some(prefix) = pattern.strip_suffix('*') {
+        return name.starts_with(prefix);
+    }
+    if let Some(suffix) = pattern.strip_prefix('*') {
+        return name.ends_with(suffix);
+    }
+    name == pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter_from(vars: &[(&str, &str)]) -> EnvTestFilter {
+        EnvTestFilter::from_lookup(|key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| (*v).to_string())
+        })
+    }
+
+    #[test]
+    fn test_filter_precedence() {
+        let all = [
+            ("FLINT_TEST", "place_fence"),
+            ("FLINT_PATTERN", "*fence"),
+            ("FLINT_TAGS", "redstone"),
+        ];
+        assert_eq!(
+            filter_from(&all),
+            EnvTestFilter::Name("place_fence".to_string())
+        );
+        assert_eq!(
+            filter_from(&all[1..]),
+            EnvTestFilter::Pattern("*fence".to_string())
+        );
+        assert_eq!(filter_from(&[]), EnvTestFilter::All);
+    }
+
+    #[test]
+    fn test_filter_tags() {
+        let filter = filter_from(&[("FLINT_TAGS", "redstone, walls,,")]);
+        assert_eq!(
+            filter,
+            EnvTestFilter::Tags(vec!["redstone".to_string(), "walls".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("place_fence", "*"));
+        assert!(matches_pattern("place_fence", "place*"));
+        assert!(matches_pattern("place_fence", "*fence"));
+        assert!(matches_pattern("place_fence", "place_fence"));
+        assert!(!matches_pattern("place_fence", "*wall"));
+    }
+}
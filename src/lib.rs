@@ -17,32 +17,40 @@
 //! ```ignore
 //!
 //! // Initialize registry and behaviors (required before creating adapter)
-//! steel_flint::init();
+//! flint_steel::init();
 //!
 //! // Create adapter
 //! let adapter = SteelAdapter::new();
 //!
-//! // Load and run tests
-//! let selector = TestSelector::new("./tests".as_ref()).unwrap();
-//! let specs = selector.load_tests(&TestFilter::all()).unwrap();
+//! // Load tests selected by FLINT_TEST / FLINT_PATTERN / FLINT_TAGS
+//! let loader = TestLoader::new("./test".as_ref(), true).unwrap();
+//! let paths = EnvTestFilter::from_env().collect_paths(&loader).unwrap();
+//! let specs: Vec<TestSpec> = paths.iter().filter_map(|p| TestSpec::from_file(p).ok()).collect();
 //!
 //! let runner = TestRunner::new(&adapter);
 //! let summary = runner.run_tests(&specs);
 //! ```
 
 mod adapter;
+mod alias;
 mod convert;
+mod filter;
 mod player;
+mod scenario;
 /// Test connection implementation for Flint tests.
 pub mod test_connection;
 mod world;
 
 pub use adapter::SteelAdapter;
+pub use alias::{AliasError, BlockAliases};
+pub use filter::EnvTestFilter;
 pub use player::SteelTestPlayer;
-pub use world::SteelTestWorld;
+pub use scenario::{ScenarioBuilder, ScenarioResult};
+pub use world::{AdapterWarning, PlacementPolicy, SteelTestWorld, WarningRecord};
 
 /// Re-export flint types for convenience
-pub use flint_core::{TestLoader, TestRunner};
+pub use flint_core::test_spec::{BlockFace, PlayerSlot};
+pub use flint_core::{Block, Item, TestLoader, TestRunner};
 
 use std::sync::{Arc, LazyLock, OnceLock};
 use steel_core::config::WordGeneratorTypes;
@@ -80,12 +80,60 @@ impl SteelTestPlayer {
         self.connection.get_events()
     }
 
+    /// Takes the connection's recorded events, leaving the log empty.
+    ///
+    /// Use this between timeline steps to assert only on the events caused
+    /// by the most recent action.
+    #[must_use]
+    pub fn drain_events(&self) -> Vec<test_connection::PlayerEvent> {
+        self.connection.drain_events()
+    }
+
     /// Clears the connection's recorded events.
     #[allow(dead_code)]
     pub fn clear_events(&self) {
         self.connection.clear_events();
     }
 
+    /// Uses the held item on a block while clicking from inside its space.
+    ///
+    /// This sets the `inside` flag of the hit result, which is how the client
+    /// reports placing into the same block space (e.g. completing a double slab
+    /// or adding candles).
+    pub fn use_item_inside(&self, pos: BlockPos, face: BlockFace) {
+        self.use_item_on_block(pos, face, true);
+    }
+
+    /// Calls the real `game_mode::use_item_on` with a hit at the block center.
+    fn use_item_on_block(&self, pos: BlockPos, face: BlockFace, inside: bool) {
+        let steel_pos = flint_pos_to_steel(pos);
+        let direction = flint_face_to_direction(face);
+
+        // Create a block hit result
+        let hit_result = BlockHitResult {
+            location: Vector3::new(
+                f64::from(steel_pos.x()) + 0.5,
+                f64::from(steel_pos.y()) + 0.5,
+                f64::from(steel_pos.z()) + 0.5,
+            ),
+            direction,
+            block_pos: steel_pos,
+            inside,
+            world_border_hit: false,
+            miss: false,
+        };
+
+        // Call the real game_mode::use_item_on
+        let result = game_mode::use_item_on(
+            &self.player,
+            &self.player.world,
+            InteractionHand::MainHand,
+            &hit_result,
+        );
+
+        tracing::debug!("use_item_on({pos:?}, {face:?}, inside: {inside}) -> {result:?}");
+    }
+
     /// Returns a reference to the underlying player.
     #[allow(dead_code)]
     #[must_use]
@@ -190,32 +238,7 @@ impl FlintPlayer for SteelTestPlayer {
     }
 
     fn use_item_on(&mut self, pos: BlockPos, face: &BlockFace) {
-        let steel_pos = flint_pos_to_steel(pos);
-        let direction = flint_face_to_direction(*face);
-
-        // Create a block hit result
-        let hit_result = BlockHitResult {
-            location: Vector3::new(
-                f64::from(steel_pos.x()) + 0.5,
-                f64::from(steel_pos.y()) + 0.5,
-                f64::from(steel_pos.z()) + 0.5,
-            ),
-            direction,
-            block_pos: steel_pos,
-            inside: false,
-            world_border_hit: false,
-            miss: false,
-        };
-
-        // Call the real game_mode::use_item_on
-        let result = game_mode::use_item_on(
-            &self.player,
-            &self.player.world,
-            InteractionHand::MainHand,
-            &hit_result,
-        );
-
-        tracing::debug!("use_item_on({pos:?}, {face:?}) -> {result:?}");
+        self.use_item_on_block(pos, *face, false);
     }
 }
 
@@ -224,7 +247,9 @@ mod tests {
     use super::*;
     use crate::init_test_registries;
     use crate::world::SteelTestWorld;
-    use flint_core::FlintWorld;
+    use flint_core::{Block, FlintWorld};
+    use steel_core::player::connection::NetworkConnection;
+    use text_components::TextComponent;
 
     #[test]
     fn test_inventory() {
@@ -260,4 +285,55 @@ mod tests {
         player.select_hotbar(10);
         assert_eq!(player.selected_hotbar(), 5);
     }
+
+    #[test]
+    fn test_drain_events() {
+        init_test_registries();
+        let world = SteelTestWorld::new();
+        let player = SteelTestPlayer::new(world.inner().clone());
+
+        // The player holds a handle to the same connection.
+        player
+            .connection
+            .disconnect_with_reason(TextComponent::plain("kicked"));
+
+        let events = player.drain_events();
+        assert!(!events.is_empty());
+        assert!(matches!(
+            events[0],
+            test_connection::PlayerEvent::Disconnected { .. }
+        ));
+        assert!(player.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_use_item_inside_completes_double_slab() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+        let slab = Block::new("minecraft:oak_slab");
+        let slab_item = Item::new("minecraft:oak_slab");
+        let slab_type = |block: &Block| block.properties.get("type").cloned();
+        world.set_block([0, 64, 0], &slab);
+        world.set_block([0, 64, 4], &slab);
+
+        let mut player = SteelTestPlayer::new(world.inner().clone());
+
+        // Clicking a side face from inside completes the clicked slab.
+        player.set_slot(PlayerSlot::Hotbar1, Some(&slab_item));
+        player.use_item_inside([0, 64, 0], BlockFace::East);
+        assert_eq!(
+            slab_type(&world.get_block([0, 64, 0])).as_deref(),
+            Some("double")
+        );
+        assert_eq!(world.get_block([1, 64, 0]).id, "minecraft:air");
+
+        // The same click from outside places a new slab next to it instead.
+        player.set_slot(PlayerSlot::Hotbar1, Some(&slab_item));
+        player.use_item_on([0, 64, 4], &BlockFace::East);
+        assert_eq!(
+            slab_type(&world.get_block([0, 64, 4])).as_deref(),
+            Some("bottom")
+        );
+        assert_eq!(world.get_block([1, 64, 4]).id, "minecraft:oak_slab");
+    }
 }
@@ -0,0 +1,517 @@
+//! Inline scenarios for writing Flint tests directly in Rust.
+//!
+//! This module provides the [`ScenarioBuilder`], which expresses the same kind of
+//! tick-accurate timeline as a JSON spec (place, fill, use, assert) through method
+//! calls. The builder produces a regular flint-core `TestSpec` and runs it through
+//! `TestRunner`, so scenarios share action ordering, block comparison and result
+//! reporting with spec files.
+
+use flint_core::test_spec::{BlockFace, PlayerSlot, TestSpec};
+use flint_core::{Block, BlockPos, FlintAdapter, Item, TestRunner, TestSummary};
+use serde_json::{Value, json};
+
+use crate::adapter::SteelAdapter;
+
+/// A single step of a scenario timeline.
+#[derive(Debug, Clone)]
+enum Step {
+    /// Place a single block.
+    Place { pos: BlockPos, block: Block },
+    /// Fill a region (inclusive) with a block.
+    Fill {
+        from: BlockPos,
+        to: BlockPos,
+        block: Block,
+    },
+    /// Put an item into a player inventory slot (or clear it).
+    SetSlot {
+        slot: PlayerSlot,
+        item: Option<Item>,
+    },
+    /// Select a hotbar slot (1-9).
+    SelectHotbar(u8),
+    /// Use the held item on a block face.
+    UseItemOn { pos: BlockPos, face: BlockFace },
+    /// Assert the block at a position.
+    AssertBlock { pos: BlockPos, block: Block },
+}
+
+impl Step {
+    /// Converts the step to its spec timeline entry (without the `at` field).
+    fn to_json(&self) -> Result<Value, serde_json::Error> {
+        Ok(match self {
+            Self::Place { pos, block } => json!({
+                "do": "place",
+                "pos": pos,
+                "block": block_json(block),
+            }),
+            Self::Fill { from, to, block } => json!({
+                "do": "fill",
+                "region": [from, to],
+                "with": block_json(block),
+            }),
+            Self::SetSlot { slot, item } => json!({
+                "do": "set_slot",
+                "slot": serde_json::to_value(slot)?,
+                "item": item.as_ref().map(|item| json!({ "id": item.id, "count": item.count })),
+            }),
+            Self::SelectHotbar(slot) => json!({
+                "do": "select_hotbar",
+                "slot": slot,
+            }),
+            Self::UseItemOn { pos, face } => json!({
+                "do": "use_item_on",
+                "pos": pos,
+                "face": serde_json::to_value(face)?,
+            }),
+            Self::AssertBlock { pos, block } => json!({
+                "do": "assert",
+                "checks": [{ "pos": pos, "is": block_json(block) }],
+            }),
+        })
+    }
+
+    /// Returns the positions this step touches, for the cleanup region.
+    fn positions(&self) -> Vec<BlockPos> {
+        match self {
+            Self::Place { pos, .. }
+            | Self::UseItemOn { pos, .. }
+            | Self::AssertBlock { pos, .. } => {
+                vec![*pos]
+            }
+            Self::Fill { from, to, .. } => vec![*from, *to],
+            Self::SetSlot { .. } | Self::SelectHotbar(_) => Vec::new(),
+        }
+    }
+}
+
+/// Converts a block to its spec representation.
+fn block_json(block: &Block) -> Value {
+    json!({ "id": block.id, "properties": block.properties })
+}
+
+/// Builder for inline Flint scenarios.
+///
+/// Steps are scheduled at the tick set by the most recent [`at`](Self::at) call
+/// (tick 0 by default). [`build`](Self::build) turns them into a `TestSpec`
+/// timeline, so execution follows exactly the same rules as a JSON spec.
+///
+/// # Example
+///
+/// ```ignore
+/// ScenarioBuilder::new("fence_connects")
+///     .place([0, 64, 0], Block::new("minecraft:oak_fence"))
+///     .place([1, 64, 0], Block::new("minecraft:stone"))
+///     .at(1)
+///     .assert_block([0, 64, 0], Block::with_properties("minecraft:oak_fence", props))
+///     .run()
+///     .assert_success();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScenarioBuilder {
+    /// Scenario name, used as the spec name.
+    name: String,
+    /// Tick that newly added steps are scheduled at.
+    tick: u64,
+    /// Scheduled steps in insertion order.
+    steps: Vec<(u64, Step)>,
+}
+
+impl ScenarioBuilder {
+    /// Creates an empty scenario with the given name.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tick: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Schedules all following steps at the given tick.
+    #[must_use]
+    pub const fn at(mut self, tick: u64) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Places a block.
+    #[must_use]
+    pub fn place(self, pos: BlockPos, block: Block) -> Self {
+        self.step(Step::Place { pos, block })
+    }
+
+    /// Fills the region between `from` and `to` (inclusive) with a block.
+    #[must_use]
+    pub fn fill(self, from: BlockPos, to: BlockPos, block: Block) -> Self {
+        self.step(Step::Fill { from, to, block })
+    }
+
+    /// Sets a player inventory slot. `None` clears the slot.
+    #[must_use]
+    pub fn set_slot(self, slot: PlayerSlot, item: Option<Item>) -> Self {
+        self.step(Step::SetSlot { slot, item })
+    }
+
+    /// Selects a hotbar slot (1-9).
+    #[must_use]
+    pub fn select_hotbar(self, slot: u8) -> Self {
+        self.step(Step::SelectHotbar(slot))
+    }
+
+    /// Uses the held item on the given block face.
+    #[must_use]
+    pub fn use_item_on(self, pos: BlockPos, face: BlockFace) -> Self {
+        self.step(Step::UseItemOn { pos, face })
+    }
+
+    /// Asserts that the block at `pos` matches `block`, using the runner's
+    /// assertion rules.
+    #[must_use]
+    pub fn assert_block(self, pos: BlockPos, block: Block) -> Self {
+        self.step(Step::AssertBlock { pos, block })
+    }
+
+    /// Builds the flint-core `TestSpec` for this scenario.
+    ///
+    /// The cleanup region is the bounding box of all positions the scenario
+    /// touches.
+    ///
+    /// # Errors
+    /// Returns an error if the generated spec does not deserialize into a
+    /// `TestSpec`, which indicates a mismatch with the flint-core spec format.
+    pub fn build(&self) -> Result<TestSpec, serde_json::Error> {
+        serde_json::from_value(self.to_json()?)
+    }
+
+    /// Converts the scenario to the JSON form of a `TestSpec`.
+    fn to_json(&self) -> Result<Value, serde_json::Error> {
+        let timeline = self
+            .steps
+            .iter()
+            .map(|(tick, step)| {
+                let mut entry = step.to_json()?;
+                entry["at"] = json!(tick);
+                Ok(entry)
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        let positions: Vec<BlockPos> = self.steps.iter().flat_map(|(_, s)| s.positions()).collect();
+        let min = bound(&positions, i32::min);
+        let max = bound(&positions, i32::max);
+
+        Ok(json!({
+            "name": self.name,
+            "description": format!("Inline scenario '{}'", self.name),
+            "setup": { "cleanup": { "region": [min, max] } },
+            "timeline": timeline,
+        }))
+    }
+
+    /// Runs the scenario against a fresh [`SteelAdapter`].
+    ///
+    /// Note: You must call `flint_steel::init()` before running a scenario.
+    ///
+    /// # Panics
+    /// Panics if the scenario cannot be converted to a `TestSpec` (see [`build`](Self::build)).
+    #[must_use]
+    pub fn run(self) -> ScenarioResult {
+        self.run_with(&SteelAdapter::new())
+    }
+
+    /// Runs the scenario through `TestRunner` against the given adapter.
+    ///
+    /// # Panics
+    /// Panics if the scenario cannot be converted to a `TestSpec` (see [`build`](Self::build)).
+    #[must_use]
+    pub fn run_with<A: FlintAdapter>(self, adapter: &A) -> ScenarioResult {
+        let spec = self
+            .build()
+            .unwrap_or_else(|e| panic!("Scenario '{}' is not a valid spec: {e}", self.name));
+
+        let runner = TestRunner::new(adapter);
+        let summary = runner.run_tests(&[spec]);
+
+        ScenarioResult {
+            name: self.name,
+            summary,
+        }
+    }
+
+    /// Schedules a step at the current tick.
+    fn step(mut self, step: Step) -> Self {
+        self.steps.push((self.tick, step));
+        self
+    }
+}
+
+/// Folds positions component-wise with `f`, defaulting to the origin.
+fn bound(positions: &[BlockPos], f: fn(i32, i32) -> i32) -> BlockPos {
+    positions
+        .iter()
+        .copied()
+        .reduce(|a, b| [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2])])
+        .unwrap_or([0, 0, 0])
+}
+
+/// Outcome of running a [`ScenarioBuilder`].
+pub struct ScenarioResult {
+    /// Scenario name.
+    pub name: String,
+    /// The runner's summary for the scenario's spec.
+    pub summary: TestSummary,
+}
+
+impl ScenarioResult {
+    /// Returns `true` if the scenario's test ran and passed.
+    #[must_use]
+    pub const fn success(&self) -> bool {
+        self.summary.passed_tests == 1 && self.summary.failed_tests == 0
+    }
+
+    /// Panics if the scenario's test failed, after printing the runner summary.
+    ///
+    /// # Panics
+    /// Panics if the scenario failed.
+    pub fn assert_success(&self) {
+        if !self.success() {
+            self.summary.print_concise_summary();
+            panic!("Scenario '{}' failed", self.name);
+        }
+    }
+}
+
+/// Declares an inline scenario and returns the resulting [`ScenarioBuilder`].
+///
+/// Each `tick => { ... }` block schedules its steps at that tick. Slots and faces
+/// are written as bare [`PlayerSlot`](crate::PlayerSlot) and
+/// [`BlockFace`](crate::BlockFace) variant names, so typos fail to compile.
+/// Blocks are an ID literal with optional `[key = value, ...]` properties.
+///
+/// Supported steps:
+/// - `place(pos, block);`
+/// - `fill(from, to, block);`
+/// - `set_slot(Slot, "item");` / `set_slot(Slot, "item", count);`
+/// - `clear_slot(Slot);`
+/// - `select_hotbar(n);`
+/// - `use_item_on(pos, Face);`
+/// - `assert_block(pos, block);`
+///
+/// # Example
+///
+/// ```ignore
+/// flint_scenario!("fence_connects", {
+///     0 => {
+///         place([0, 64, 0], "minecraft:oak_fence");
+///         place([1, 64, 0], "minecraft:stone");
+///     },
+///     1 => {
+///         assert_block([0, 64, 0], "minecraft:oak_fence"[east = true]);
+///     },
+/// })
+/// .run()
+/// .assert_success();
+/// ```
+#[macro_export]
+macro_rules! flint_scenario {
+    ($name:expr, { $($tick:literal => { $($step:ident($($args:tt)*);)* }),* $(,)? }) => {{
+        // Steps expand side by side rather than recursively, so tick blocks are
+        // not limited by the macro recursion limit
+        #[allow(unused_mut)]
+        let mut builder = $crate::ScenarioBuilder::new($name);
+        $(
+            builder = builder.at($tick);
+            $(
+                builder = $crate::flint_scenario!(@step builder, $step($($args)*));
+            )*
+        )*
+        builder
+    }};
+
+    (@step $b:ident, place($pos:expr, $id:literal $([$($k:ident = $v:literal),* $(,)?])?)) => {
+        $b.place($pos, $crate::flint_scenario!(@block $id $([$($k = $v),*])?))
+    };
+    (@step $b:ident, fill($from:expr, $to:expr, $id:literal $([$($k:ident = $v:literal),* $(,)?])?)) => {
+        $b.fill($from, $to, $crate::flint_scenario!(@block $id $([$($k = $v),*])?))
+    };
+    (@step $b:ident, set_slot($slot:ident, $id:literal)) => {
+        $b.set_slot($crate::PlayerSlot::$slot, Some($crate::Item::new($id)))
+    };
+    (@step $b:ident, set_slot($slot:ident, $id:literal, $count:expr)) => {
+        $b.set_slot($crate::PlayerSlot::$slot, Some({
+            let mut item = $crate::Item::new($id);
+            item.count = $count;
+            item
+        }))
+    };
+    (@step $b:ident, clear_slot($slot:ident)) => {
+        $b.set_slot($crate::PlayerSlot::$slot, None)
+    };
+    (@step $b:ident, select_hotbar($slot:expr)) => {
+        $b.select_hotbar($slot)
+    };
+    (@step $b:ident, use_item_on($pos:expr, $face:ident)) => {
+        $b.use_item_on($pos, $crate::BlockFace::$face)
+    };
+    (@step $b:ident, assert_block($pos:expr, $id:literal $([$($k:ident = $v:literal),* $(,)?])?)) => {
+        $b.assert_block($pos, $crate::flint_scenario!(@block $id $([$($k = $v),*])?))
+    };
+
+    (@block $id:literal) => { $crate::Block::new($id) };
+    (@block $id:literal [$($k:ident = $v:literal),*]) => {{
+        let mut block = $crate::Block::new($id);
+        $(
+            block
+                .properties
+                .insert(stringify!($k).to_string(), $v.to_string());
+        )*
+        block
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::init_test_registries;
+
+    #[test]
+    fn test_scenario_builds_spec() {
+        let scenario = ScenarioBuilder::new("place_stone")
+            .fill([2, 64, -1], [0, 65, 3], Block::new("minecraft:stone"))
+            .at(1)
+            .assert_block([1, 63, 0], Block::new("minecraft:air"));
+
+        let spec = scenario.to_json().expect("scenario should convert to JSON");
+        let timeline = spec["timeline"].as_array().expect("timeline array");
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0]["at"], 0);
+        assert_eq!(timeline[1]["at"], 1);
+        assert_eq!(
+            spec["setup"]["cleanup"]["region"],
+            json!([[0, 63, -1], [2, 65, 3]])
+        );
+
+        scenario
+            .build()
+            .unwrap_or_else(|e| panic!("Scenario should build a valid spec: {e}"));
+    }
+
+    #[test]
+    fn test_bound() {
+        let positions = [[3, 64, -2], [-1, 70, 5]];
+        assert_eq!(bound(&positions, i32::min), [-1, 64, -2]);
+        assert_eq!(bound(&positions, i32::max), [3, 70, 5]);
+        assert_eq!(bound(&[], i32::min), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_scenario_place_and_assert() {
+        init_test_registries();
+        ScenarioBuilder::new("place_stone")
+            .place([0, 64, 0], Block::new("minecraft:stone"))
+            .at(1)
+            .assert_block([0, 64, 0], Block::new("minecraft:stone"))
+            .run()
+            .assert_success();
+    }
+
+    #[test]
+    fn test_scenario_fill_and_assert() {
+        init_test_registries();
+        ScenarioBuilder::new("fill_stone")
+            .fill([0, 64, 0], [1, 64, 1], Block::new("minecraft:stone"))
+            .at(1)
+            .assert_block([0, 64, 0], Block::new("minecraft:stone"))
+            .assert_block([1, 64, 1], Block::new("minecraft:stone"))
+            .run()
+            .assert_success();
+    }
+
+    #[test]
+    fn test_scenario_use_item_on() {
+        init_test_registries();
+        ScenarioBuilder::new("use_stone")
+            .place([0, 64, 0], Block::new("minecraft:stone"))
+            .set_slot(PlayerSlot::Hotbar1, Some(Item::new("minecraft:stone")))
+            .select_hotbar(1)
+            .at(1)
+            .use_item_on([0, 64, 0], BlockFace::Top)
+            .at(2)
+            .assert_block([0, 65, 0], Block::new("minecraft:stone"))
+            .run()
+            .assert_success();
+    }
+
+    #[test]
+    fn test_scenario_reports_failures() {
+        init_test_registries();
+        let result = ScenarioBuilder::new("wrong_block")
+            .fill([0, 64, 0], [1, 64, 0], Block::new("minecraft:stone"))
+            .at(2)
+            .assert_block([1, 64, 0], Block::new("minecraft:dirt"))
+            .run();
+
+        assert!(!result.success());
+        assert_eq!(result.summary.failed_tests, 1);
+    }
+
+    #[test]
+    fn test_scenario_macro() {
+        init_test_registries();
+        crate::flint_scenario!("macro_slab", {
+            0 => {
+                place([0, 64, 0], "minecraft:oak_slab"[type = "top"]);
+                set_slot(Hotbar1, "minecraft:stone", 2);
+                select_hotbar(1);
+            },
+            1 => {
+                assert_block([0, 64, 0], "minecraft:oak_slab"[type = "top", waterlogged = false]);
+            },
+        })
+        .run()
+        .assert_success();
+    }
+
+    #[test]
+    fn test_scenario_macro_many_steps() {
+        // More steps in one tick block than the default recursion limit (128)
+        let builder = crate::flint_scenario!("many_steps", {
+            0 => {
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+                select_hotbar(1); select_hotbar(2); select_hotbar(3); select_hotbar(4);
+                select_hotbar(5); select_hotbar(6); select_hotbar(7); select_hotbar(8);
+            },
+        });
+
+        assert_eq!(builder.steps.len(), 144);
+    }
+}
@@ -56,6 +56,14 @@ impl FlintConnection {
         self.events.lock().clone()
     }
 
+    /// Takes all recorded events, leaving the log empty.
+    ///
+    /// Unlike calling `get_events` followed by `clear_events`, no event
+    /// recorded in between can be lost.
+    pub fn drain_events(&self) -> Vec<PlayerEvent> {
+        std::mem::take(&mut *self.events.lock())
+    }
+
     /// Clears all recorded events.
     pub fn clear_events(&self) {
         self.events.lock().clear();
@@ -122,3 +130,30 @@ impl NetworkConnection for FlintConnection {
         self.closed.load(Ordering::Relaxed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_events() {
+        let connection = FlintConnection::new();
+        let handle = connection.clone();
+        {
+            let mut events = connection.events.lock();
+            events.push(PlayerEvent::PacketSent { data: vec![1, 2] });
+            events.push(PlayerEvent::Disconnected {
+                reason: "test".to_string(),
+            });
+        }
+        assert_eq!(handle.event_count(), 2);
+
+        let drained = handle.drain_events();
+        assert_eq!(drained.len(), 2);
+        assert!(matches!(&drained[0], PlayerEvent::PacketSent { data } if data == &[1, 2]));
+        assert!(matches!(&drained[1], PlayerEvent::Disconnected { reason } if reason == "test"));
+
+        assert_eq!(connection.event_count(), 0);
+        assert!(connection.drain_events().is_empty());
+    }
+}
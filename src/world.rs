@@ -3,6 +3,8 @@
 //! This module provides a test world that wraps the real `Arc<World>` from steel-core,
 //! configured with RAM-only storage for instant chunk creation without disk I/O.
 
+use std::fmt;
+use std::ops::Range;
 use std::sync::{
     Arc,
     atomic::{AtomicU64, Ordering},
@@ -20,12 +22,128 @@ use steel_core::chunk::section::{ChunkSection, Sections};
 use steel_core::chunk::world_gen_context::ChunkGeneratorType;
 use steel_core::world::{World, WorldConfig, WorldStorageConfig};
 use steel_registry::vanilla_dimension_types::OVERWORLD;
+use steel_utils::locks::SyncMutex;
 use steel_utils::{BlockPos, ChunkPos, types::UpdateFlags};
 
+use crate::alias::BlockAliases;
 use crate::convert::{flint_block_to_state_id, flint_pos_to_steel, state_id_to_block};
 use crate::player::SteelTestPlayer;
 use crate::runtime;
 
+/// Block state property used by Steel to model waterlogged blocks.
+const WATERLOGGED: &str = "waterlogged";
+
+/// Blocks whose fluid state is always water, so they have no `waterlogged`
+/// property. Steel has no fluid state lookup we can use here, so this list
+/// mirrors vanilla's water-only blocks.
+const ALWAYS_WATERLOGGED: &[&str] = &[
+    "minecraft:water",
+    "minecraft:bubble_column",
+    "minecraft:kelp",
+    "minecraft:kelp_plant",
+    "minecraft:seagrass",
+    "minecraft:tall_seagrass",
+];
+
+/// Block ID returned for positions outside the build height.
+const VOID_AIR: &str = "minecraft:void_air";
+
+/// Block ID (without namespace) that marks "leave this position untouched" in structures.
+const STRUCTURE_VOID: &str = "structure_void";
+
+/// How a placement treats blocks that already exist at the target position.
+///
+/// This allows fixtures to be layered on top of each other without clobbering
+/// previously placed contraption parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlacementPolicy {
+    /// Overwrite whatever is already there (same as `FlintWorld::set_block`).
+    #[default]
+    ReplaceAll,
+    /// Only place into positions that are currently air.
+    KeepExisting,
+    /// Overwrite existing blocks, but treat `minecraft:structure_void` as
+    /// "keep the existing block" instead of placing it.
+    StructureVoidSkips,
+}
+
+/// Returns whether the block ID is one of the air variants.
+fn is_air(id: &str) -> bool {
+    matches!(
+        id,
+        "minecraft:air" | "minecraft:cave_air" | "minecraft:void_air"
+    )
+}
+
+/// A non-fatal problem the adapter ran into while executing a test.
+///
+/// These are recorded in addition to being logged. flint-core has no hook for
+/// adapter warnings yet, so they are only readable from a concrete
+/// [`SteelTestWorld`] and do not show up in `TestRunner` results. Repeated
+/// warnings from the same source are merged into one [`WarningRecord`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdapterWarning {
+    /// A block ID (or one of its properties) is unknown and was not placed.
+    UnknownBlock {
+        /// First position the block was meant to be placed at.
+        pos: FlintBlockPos,
+        /// The block ID as written in the spec.
+        id: String,
+    },
+    /// A placement was skipped because the position is outside the build height.
+    OutsideBuildHeight {
+        /// First position a block was meant to be placed at.
+        pos: FlintBlockPos,
+    },
+    /// A chunk could not be loaded, so reads and writes in it have no effect.
+    ChunkLoadFailed {
+        /// Chunk coordinates `[x, z]`.
+        chunk: [i32; 2],
+        /// The storage error message.
+        error: String,
+    },
+}
+
+impl AdapterWarning {
+    /// Returns whether two warnings come from the same source and should be
+    /// merged: the same block ID, the same chunk, or any out-of-range placement.
+    fn same_source(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::UnknownBlock { id: a, .. }, Self::UnknownBlock { id: b, .. }) => a == b,
+            (Self::OutsideBuildHeight { .. }, Self::OutsideBuildHeight { .. }) => true,
+            (Self::ChunkLoadFailed { chunk: a, .. }, Self::ChunkLoadFailed { chunk: b, .. }) => {
+                a == b
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AdapterWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownBlock { pos, id } => {
+                write!(f, "unknown block {id} at {pos:?} was skipped")
+            }
+            Self::OutsideBuildHeight { pos } => {
+                write!(f, "placement at {pos:?} outside build height was skipped")
+            }
+            Self::ChunkLoadFailed { chunk, error } => {
+                write!(f, "failed to load chunk {chunk:?}: {error}")
+            }
+        }
+    }
+}
+
+/// A recorded warning and how many times it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarningRecord {
+    /// The first occurrence of the warning.
+    pub warning: AdapterWarning,
+    /// How many times a warning from the same source was recorded.
+    pub count: usize,
+}
+
 /// Test world implementation using the real steel-core World.
 ///
 /// This wraps an `Arc<World>` configured with RAM-only storage:
@@ -38,6 +156,10 @@ pub struct SteelTestWorld {
     world: Arc<World>,
     /// Current tick count (for `FlintWorld` trait).
     tick: AtomicU64,
+    /// Block ID aliases applied when placing blocks.
+    aliases: Arc<BlockAliases>,
+    /// Warnings recorded since the last `take_warnings` call.
+    warnings: SyncMutex<Vec<WarningRecord>>,
 }
 
 impl SteelTestWorld {
@@ -48,9 +170,17 @@ impl SteelTestWorld {
     ///
     /// # Panic
     /// shouldn't panic only something is completely broken and then it is ok
-    #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn new() -> Self {
+        Self::with_aliases(Arc::default())
+    }
+
+    /// Creates a new test world that resolves block IDs through `aliases`.
+    ///
+    /// See [`new`](Self::new) for the world setup.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn with_aliases(aliases: Arc<BlockAliases>) -> Self {
         let rt = runtime();
 
         // Create world with RAM-only storage
@@ -69,6 +199,8 @@ impl SteelTestWorld {
         Self {
             world,
             tick: AtomicU64::new(0),
+            aliases,
+            warnings: SyncMutex::new(Vec::new()),
         }
     }
 
@@ -78,6 +210,175 @@ impl SteelTestWorld {
         &self.world
     }
 
+    /// Returns the range of Y coordinates blocks can be placed at.
+    ///
+    /// This comes from the world's dimension type (e.g. `-64..320` for the
+    /// overworld), so specs can be checked against the adapter's real bounds.
+    #[must_use]
+    pub fn build_height(&self) -> Range<i32> {
+        let dimension = &self.world.dimension;
+        dimension.min_y..dimension.min_y + dimension.height
+    }
+
+    /// Returns whether the given position is within the world's build height.
+    #[must_use]
+    pub fn is_in_build_height(&self, pos: FlintBlockPos) -> bool {
+        self.build_height().contains(&pos[1])
+    }
+
+    /// Gets all warnings recorded so far.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<WarningRecord> {
+        self.warnings.lock().clone()
+    }
+
+    /// Takes all recorded warnings, leaving the list empty.
+    pub fn take_warnings(&self) -> Vec<WarningRecord> {
+        std::mem::take(&mut *self.warnings.lock())
+    }
+
+    /// Records a warning for later inspection, merging it into an existing
+    /// record from the same source.
+    fn warn(&self, warning: AdapterWarning) {
+        let mut warnings = self.warnings.lock();
+        if let Some(record) = warnings
+            .iter_mut()
+            .find(|record| record.warning.same_source(&warning))
+        {
+            record.count += 1;
+        } else {
+            warnings.push(WarningRecord { warning, count: 1 });
+        }
+    }
+
+    /// Places a block according to the given [`PlacementPolicy`].
+    ///
+    /// Returns `true` if the block was placed, `false` if the policy skipped it or
+    /// the placement itself was skipped (unknown block, outside build height).
+    pub fn place_with_policy(
+        &mut self,
+        pos: FlintBlockPos,
+        block: &Block,
+        policy: PlacementPolicy,
+    ) -> bool {
+        let place = match policy {
+            PlacementPolicy::ReplaceAll => true,
+            PlacementPolicy::KeepExisting => is_air(&self.get_block(pos).id),
+            PlacementPolicy::StructureVoidSkips => {
+                block.id.strip_prefix("minecraft:").unwrap_or(&block.id) != STRUCTURE_VOID
+            }
+        };
+
+        place && self.try_set_block(pos, block)
+    }
+
+    /// Fills the region between `from` and `to` (inclusive, in any corner order)
+    /// according to the given [`PlacementPolicy`].
+    ///
+    /// Returns the number of blocks that were actually placed.
+    pub fn fill_with_policy(
+        &mut self,
+        from: FlintBlockPos,
+        to: FlintBlockPos,
+        block: &Block,
+        policy: PlacementPolicy,
+    ) -> usize {
+        let mut placed = 0;
+        for x in from[0].min(to[0])..=from[0].max(to[0]) {
+            for y in from[1].min(to[1])..=from[1].max(to[1]) {
+                for z in from[2].min(to[2])..=from[2].max(to[2]) {
+                    if self.place_with_policy([x, y, z], block, policy) {
+                        placed += 1;
+                    }
+                }
+            }
+        }
+        placed
+    }
+
+    /// Returns whether the block at the given position is waterlogged.
+    ///
+    /// Steel models waterlogging as a block state property, so blocks without
+    /// a `waterlogged` property report `false`. The exception are blocks that are
+    /// always filled with water (water itself, bubble columns, kelp and seagrass),
+    /// which report `true`.
+    #[must_use]
+    pub fn is_waterlogged(&self, pos: FlintBlockPos) -> bool {
+        let block = self.get_block(pos);
+        ALWAYS_WATERLOGGED.contains(&block.id.as_str())
+            || block
+                .properties
+                .get(WATERLOGGED)
+                .is_some_and(|value| value == "true")
+    }
+
+    /// Waterlogs the block at the given position.
+    ///
+    /// Returns `false` if the block has no `waterlogged` property, which includes
+    /// blocks that are always waterlogged.
+    pub fn waterlog(&mut self, pos: FlintBlockPos) -> bool {
+        self.set_waterlogged(pos, true)
+    }
+
+    /// Drains the water from the block at the given position.
+    ///
+    /// Returns `false` if the block has no `waterlogged` property, which includes
+    /// blocks that are always waterlogged.
+    pub fn drain(&mut self, pos: FlintBlockPos) -> bool {
+        self.set_waterlogged(pos, false)
+    }
+
+    /// Sets the `waterlogged` property of the block at `pos`, keeping all
+    /// other properties unchanged.
+    fn set_waterlogged(&mut self, pos: FlintBlockPos, waterlogged: bool) -> bool {
+        let mut block = self.get_block(pos);
+        if !block.properties.contains_key(WATERLOGGED) {
+            return false;
+        }
+
+        block
+            .properties
+            .insert(WATERLOGGED.to_string(), waterlogged.to_string());
+        self.try_set_block(pos, &block)
+    }
+
+    /// Places a block, returning `false` if the placement was skipped.
+    ///
+    /// Placements are skipped (and recorded as warnings) for unknown blocks and
+    /// positions outside the build height.
+    fn try_set_block(&mut self, pos: FlintBlockPos, block: &Block) -> bool {
+        let Some(state_id) = flint_block_to_state_id(block, &self.aliases) else {
+            tracing::warn!("Unknown block: {} - skipping placement", block.id);
+            self.warn(AdapterWarning::UnknownBlock {
+                pos,
+                id: block.id.clone(),
+            });
+            return false;
+        };
+
+        if !self.is_in_build_height(pos) {
+            tracing::warn!(
+                "Position {pos:?} is outside build height {:?} - skipping placement",
+                self.build_height()
+            );
+            self.warn(AdapterWarning::OutsideBuildHeight { pos });
+            return false;
+        }
+
+        let steel_pos = flint_pos_to_steel(pos);
+
+        // Ensure the chunk is loaded before setting blocks
+        self.ensure_chunk_at(&steel_pos);
+
+        // Use the real World::set_block which handles:
+        // - Neighbor updates
+        // - Shape updates
+        // - Block behavior callbacks (on_place, etc.)
+        self.world
+            .set_block(steel_pos, state_id, UpdateFlags::UPDATE_ALL);
+        true
+    }
+
     /// Ensures the chunk containing the given block position is loaded.
     ///
     /// This is intended for testing only. It blocks until the chunk is loaded
@@ -156,6 +457,10 @@ impl SteelTestWorld {
             }
             Err(e) => {
                 tracing::error!("Failed to load chunk {chunk_pos:?}: {e}");
+                self.warn(AdapterWarning::ChunkLoadFailed {
+                    chunk: [chunk_x, chunk_z],
+                    error: e.to_string(),
+                });
             }
         }
     }
@@ -181,6 +486,11 @@ impl FlintWorld for SteelTestWorld {
     }
 
     fn get_block(&self, pos: FlintBlockPos) -> Block {
+        // Outside the build height there is only void air, like in vanilla
+        if !self.is_in_build_height(pos) {
+            return Block::new(VOID_AIR);
+        }
+
         let steel_pos = flint_pos_to_steel(pos);
 
         // Ensure the chunk is loaded (for RAM storage this creates empty chunks)
@@ -191,22 +501,7 @@ impl FlintWorld for SteelTestWorld {
     }
 
     fn set_block(&mut self, pos: FlintBlockPos, block: &Block) {
-        let Some(state_id) = flint_block_to_state_id(block) else {
-            tracing::warn!("Unknown block: {} - skipping placement", block.id);
-            return;
-        };
-
-        let steel_pos = flint_pos_to_steel(pos);
-
-        // Ensure the chunk is loaded before setting blocks
-        self.ensure_chunk_at(&steel_pos);
-
-        // Use the real World::set_block which handles:
-        // - Neighbor updates
-        // - Shape updates
-        // - Block behavior callbacks (on_place, etc.)
-        self.world
-            .set_block(steel_pos, state_id, UpdateFlags::UPDATE_ALL);
+        self.try_set_block(pos, block);
     }
 
     fn create_player(&mut self) -> Box<dyn FlintPlayer> {
@@ -289,4 +584,183 @@ mod tests {
             retrieved.id
         );
     }
+
+    #[test]
+    fn test_waterlog_and_drain() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+
+        let slab = Block::new("minecraft:oak_slab");
+        world.set_block([0, 64, 0], &slab);
+        assert!(!world.is_waterlogged([0, 64, 0]));
+
+        assert!(world.waterlog([0, 64, 0]));
+        assert!(world.is_waterlogged([0, 64, 0]));
+        assert_eq!(world.get_block([0, 64, 0]).id, "minecraft:oak_slab");
+
+        assert!(world.drain([0, 64, 0]));
+        assert!(!world.is_waterlogged([0, 64, 0]));
+    }
+
+    #[test]
+    fn test_waterlog_unsupported_block() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+
+        let stone = Block::new("minecraft:stone");
+        world.set_block([0, 64, 0], &stone);
+
+        assert!(!world.waterlog([0, 64, 0]));
+        assert!(!world.is_waterlogged([0, 64, 0]));
+    }
+
+    #[test]
+    fn test_keep_existing_policy() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+
+        let stone = Block::new("minecraft:stone");
+        world.set_block([1, 64, 0], &stone);
+
+        let dirt = Block::new("minecraft:dirt");
+        let placed =
+            world.fill_with_policy([0, 64, 0], [2, 64, 0], &dirt, PlacementPolicy::KeepExisting);
+
+        assert_eq!(placed, 2);
+        assert_eq!(world.get_block([0, 64, 0]).id, "minecraft:dirt");
+        assert_eq!(world.get_block([1, 64, 0]).id, "minecraft:stone");
+        assert_eq!(world.get_block([2, 64, 0]).id, "minecraft:dirt");
+    }
+
+    #[test]
+    fn test_structure_void_skips_policy() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+
+        let stone = Block::new("minecraft:stone");
+        world.set_block([0, 64, 0], &stone);
+
+        let void = Block::new("minecraft:structure_void");
+        assert!(!world.place_with_policy([0, 64, 0], &void, PlacementPolicy::StructureVoidSkips));
+        assert_eq!(world.get_block([0, 64, 0]).id, "minecraft:stone");
+
+        let bare_void = Block::new("structure_void");
+        assert!(!world.place_with_policy(
+            [0, 64, 0],
+            &bare_void,
+            PlacementPolicy::StructureVoidSkips
+        ));
+        assert_eq!(world.get_block([0, 64, 0]).id, "minecraft:stone");
+
+        let dirt = Block::new("minecraft:dirt");
+        assert!(world.place_with_policy([0, 64, 0], &dirt, PlacementPolicy::StructureVoidSkips));
+        assert_eq!(world.get_block([0, 64, 0]).id, "minecraft:dirt");
+    }
+
+    #[test]
+    fn test_build_height() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+        let height = world.build_height();
+
+        assert!(world.is_in_build_height([0, height.start, 0]));
+        assert!(!world.is_in_build_height([0, height.start - 1, 0]));
+        assert!(!world.is_in_build_height([0, height.end, 0]));
+
+        // Placements outside the build height are skipped and reported
+        let stone = Block::new("minecraft:stone");
+        assert!(!world.place_with_policy([0, height.end, 0], &stone, PlacementPolicy::ReplaceAll));
+        assert!(world.place_with_policy(
+            [0, height.end - 1, 0],
+            &stone,
+            PlacementPolicy::ReplaceAll
+        ));
+        assert_eq!(
+            world.take_warnings(),
+            vec![WarningRecord {
+                warning: AdapterWarning::OutsideBuildHeight {
+                    pos: [0, height.end, 0],
+                },
+                count: 1,
+            }]
+        );
+        assert_eq!(
+            world.get_block([0, height.end - 1, 0]).id,
+            "minecraft:stone"
+        );
+    }
+
+    #[test]
+    fn test_warnings_recorded() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+        assert!(world.warnings().is_empty());
+
+        // Repeated warnings from the same source are merged
+        let unknown = Block::new("minecraft:not_a_block");
+        world.fill_with_policy(
+            [0, 64, 0],
+            [2, 64, 0],
+            &unknown,
+            PlacementPolicy::ReplaceAll,
+        );
+
+        let stone = Block::new("minecraft:stone");
+        let too_high = world.build_height().end;
+        world.set_block([0, too_high, 0], &stone);
+
+        assert_eq!(
+            world.take_warnings(),
+            vec![
+                WarningRecord {
+                    warning: AdapterWarning::UnknownBlock {
+                        pos: [0, 64, 0],
+                        id: "minecraft:not_a_block".to_string(),
+                    },
+                    count: 3,
+                },
+                WarningRecord {
+                    warning: AdapterWarning::OutsideBuildHeight {
+                        pos: [0, too_high, 0],
+                    },
+                    count: 1,
+                },
+            ]
+        );
+        assert!(world.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_policy_counts_only_real_placements() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+        let top = world.build_height().end;
+
+        // The top position is outside the build height and reads back as void air
+        let stone = Block::new("minecraft:stone");
+        let placed = world.fill_with_policy(
+            [0, top - 1, 0],
+            [0, top, 0],
+            &stone,
+            PlacementPolicy::KeepExisting,
+        );
+        assert_eq!(placed, 1);
+
+        let unknown = Block::new("minecraft:not_a_block");
+        assert!(!world.place_with_policy([1, 64, 0], &unknown, PlacementPolicy::ReplaceAll));
+    }
+
+    #[test]
+    fn test_always_waterlogged_blocks() {
+        init_test_registries();
+        let mut world = SteelTestWorld::new();
+
+        let water = Block::new("minecraft:water");
+        world.set_block([0, 64, 0], &water);
+        assert!(world.is_waterlogged([0, 64, 0]));
+
+        // There is no property to toggle, so draining is not supported
+        assert!(!world.drain([0, 64, 0]));
+        assert!(world.is_waterlogged([0, 64, 0]));
+    }
 }